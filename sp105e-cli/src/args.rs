@@ -21,8 +21,51 @@ pub(super) enum CliCommand {
     Speed { up: u8 },
     /// Set the brightness. Range = [0, 6].
     Brightness { up: u8 },
+    /// Set the speed to an absolute level, nudging as many steps as needed. Range = [0, 6].
+    SetSpeed { level: u8 },
+    /// Set the brightness to an absolute level, nudging as many steps as needed. Range = [0, 6].
+    SetBrightness { level: u8 },
+    /// Resolve a trigger from a rules config (YAML/TOML) and dispatch its command sequence.
+    Run {
+        /// Path to a rules config file (.yaml/.yml/.toml).
+        config: std::path::PathBuf,
+        /// Name of the trigger to resolve.
+        trigger: String,
+    },
+    /// Print status whenever it changes, polling at the given interval.
+    Watch {
+        /// Polling interval in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+    },
     /// Get status information from the controller.
     Status,
+    /// Continuously sync the strip's color to the host's screen.
+    Ambient {
+        /// Sampling interval in milliseconds.
+        #[arg(long, default_value_t = 100)]
+        rate_ms: u64,
+        /// Weight given to the frame's edge region vs. its center, in [0.0, 1.0].
+        #[arg(long, default_value_t = 0.0)]
+        edge_weight: f32,
+    },
+    /// Smoothly fade to a new color.
+    Fade {
+        r: u8,
+        g: u8,
+        b: u8,
+        /// Duration of the fade, in milliseconds.
+        #[arg(long, default_value_t = 1000)]
+        ms: u64,
+        /// Number of intermediate steps.
+        #[arg(long, default_value_t = 30)]
+        steps: u32,
+        /// Starting color as "r,g,b". The controller can't report its
+        /// actual current color (see `StatusResp::mode`), so this must be
+        /// supplied explicitly; defaults to black if omitted.
+        #[arg(long, value_name = "r,g,b")]
+        from: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -44,7 +87,11 @@ pub(super) struct Cli {
     #[arg(short, long, value_name = "01:23:45:67:89:ab")]
     pub adapter: Option<String>,
 
-    /// MAC of the target SP105E device.
-    #[arg(short, long, value_name = "01:23:45:67:89:ab")]
+    /// MAC of the target SP105E device. Accepts a comma-separated list to
+    /// run the verb across a synchronized group of controllers instead of
+    /// one. Only verbs that resolve to a single command plus `SetSpeed`
+    /// and `SetBrightness` support a group; `Status`, `Ambient`, `Fade`,
+    /// `Run`, and `Watch` require exactly one target.
+    #[arg(short, long, value_name = "01:23:45:67:89:ab[,01:23:45:67:89:ac,...]")]
     pub target: String,
 }
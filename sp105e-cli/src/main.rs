@@ -1,7 +1,8 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use args::{CliCommand, FixedColor};
 use clap::Parser;
+use futures_util::{pin_mut, StreamExt};
 use sp105e::{
     client::LEDClient,
     commands::{Command, StatusResp},
@@ -9,6 +10,53 @@ use sp105e::{
 
 mod args;
 
+/// Maps a verb that resolves to a single, immediate `Command` (as opposed
+/// to `Status`/`Ambient`/`Fade`/`Run`/etc., which need a live `LEDClient`
+/// and are handled separately). Used by both the single-target and group
+/// dispatch paths.
+fn simple_command(verb: CliCommand) -> Result<Command> {
+    Ok(match verb {
+        CliCommand::Power => Command::Power,
+        CliCommand::SetPixel { pixel } => Command::SetPixelType(pixel),
+        CliCommand::SetNumber { num } => Command::SetPixels(num),
+        CliCommand::SetOrder { order } => Command::SetColorOrder(order),
+        CliCommand::SetColor { r, g, b } => Command::Color([r, g, b]),
+        CliCommand::SetFixedColor { color } => match color {
+            FixedColor::Red => Command::FixedRed,
+            FixedColor::Green => Command::FixedGreen,
+            FixedColor::Blue => Command::FixedBlue,
+            FixedColor::White => Command::FixedWhite1,
+            FixedColor::AltWhite => Command::FixedWhite2,
+        },
+        CliCommand::SetAnimation { id } => Command::Animation(id),
+        CliCommand::Speed { up } => {
+            if up > 0 {
+                Command::SpeedUp
+            } else {
+                Command::SpeedDown
+            }
+        }
+        CliCommand::Brightness { up } => {
+            if up > 0 {
+                Command::BrightnessUp
+            } else {
+                Command::BrightnessDown
+            }
+        }
+        other => return Err(anyhow!("{other:?} is not a single immediate command")),
+    })
+}
+
+/// Parses a "r,g,b" triplet, e.g. as given to `Fade`'s `--from`.
+fn parse_rgb(spec: &str) -> Result<[u8; 3]> {
+    let parts: Vec<&str> = spec.split(',').map(|p| p.trim()).collect();
+    let [r, g, b]: [&str; 3] = parts
+        .try_into()
+        .map_err(|_| anyhow!("expected \"r,g,b\", got {spec:?}"))?;
+
+    Ok([r.parse()?, g.parse()?, b.parse()?])
+}
+
 async fn pretty_print_status(status: &StatusResp) -> Result<()> {
     println!("Power      : {:#04x}", status.power);
     println!(
@@ -28,10 +76,7 @@ async fn pretty_print_status(status: &StatusResp) -> Result<()> {
         status.color_order.clone() as u8,
         status.color_order
     );
-    println!(
-        "Unknown    : {:#04x} {:#04x}",
-        status._unknown[0], status._unknown[1]
-    );
+    println!("Controller : {:#06x}", status.controller_id);
 
     Ok(())
 }
@@ -40,43 +85,95 @@ async fn pretty_print_status(status: &StatusResp) -> Result<()> {
 pub async fn main() -> Result<()> {
     let cli = args::Cli::parse();
 
-    let client = LEDClient::new(cli.adapter, cli.target).await?;
-    let command = match cli.verb {
-        CliCommand::Power => Command::Power,
-        CliCommand::SetPixel { pixel } => Command::SetPixelType(pixel),
-        CliCommand::SetOrder { order } => Command::SetColorOrder(order),
-        CliCommand::SetColor { r, g, b } => Command::Color([r, g, b]),
-        CliCommand::SetFixedColor { color } => match color {
-            FixedColor::Red => Command::FixedRed,
-            FixedColor::Green => Command::FixedGreen,
-            FixedColor::Blue => Command::FixedBlue,
-            FixedColor::White => Command::FixedWhite1,
-            FixedColor::AltWhite => Command::FixedWhite2,
-        },
-        CliCommand::SetAnimation { id } => Command::Animation(id),
-        CliCommand::Speed { up } => {
-            if up > 0 {
-                Command::SpeedUp
-            } else {
-                Command::SpeedDown
-            }
-        }
-        CliCommand::Brightness { up } => {
-            if up > 0 {
-                Command::BrightnessUp
-            } else {
-                Command::BrightnessDown
+    let targets: Vec<String> = cli
+        .target
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    if targets.len() > 1 {
+        let group = LEDClient::group(cli.adapter, targets).await?;
+
+        return match cli.verb {
+            CliCommand::SetSpeed { level } => group.set_speed(level).await,
+            CliCommand::SetBrightness { level } => group.set_brightness(level).await,
+            verb => {
+                let command = simple_command(verb).map_err(|_| {
+                    anyhow!(
+                        "only single immediate commands, SetSpeed and SetBrightness can target \
+                         a group; Status/Ambient/Fade/Run/Watch need a single --target"
+                    )
+                })?;
+                group.send_cmd(&command).await
             }
-        }
-        CliCommand::GetState => Command::Status,
-    };
+        };
+    }
 
-    match command {
-        Command::Status => {
+    let client = LEDClient::new(
+        cli.adapter,
+        targets
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("--target must not be empty"))?,
+    )
+    .await?;
+
+    match cli.verb {
+        CliCommand::Status => {
             let status = client.get_status().await?;
             pretty_print_status(&status).await?;
         }
-        c => client.send_cmd(&c).await?,
+        CliCommand::Ambient {
+            rate_ms,
+            edge_weight,
+        } => {
+            let config = sp105e::ambient::AmbientConfig {
+                sample_rate: std::time::Duration::from_millis(rate_ms),
+                edge_weight,
+            };
+            client.run_ambient(config).await?;
+        }
+        CliCommand::Fade {
+            r,
+            g,
+            b,
+            ms,
+            steps,
+            from,
+        } => {
+            // The controller doesn't report the actual custom RGB value in
+            // its status (see `StatusResp::mode`), so the real starting
+            // color is never recoverable from hardware; the caller must
+            // supply it via `--from`, or we just start from black.
+            let from = match from {
+                Some(spec) => parse_rgb(&spec)?,
+                None => [0, 0, 0],
+            };
+
+            client
+                .fade(from, [r, g, b], std::time::Duration::from_millis(ms), steps)
+                .await?;
+        }
+        CliCommand::SetSpeed { level } => client.set_speed(level).await?,
+        CliCommand::SetBrightness { level } => client.set_brightness(level).await?,
+        CliCommand::Run { config, trigger } => {
+            let config = sp105e::rules::RulesConfig::load(&config)?;
+            client.run_rule(&config, &trigger).await?;
+        }
+        CliCommand::Watch { interval_ms } => {
+            let stream = client.watch_status(std::time::Duration::from_millis(interval_ms));
+            pin_mut!(stream);
+            while let Some(status) = stream.next().await {
+                let status = status?;
+                println!("--- changed: {:?} ---", status.changed);
+                pretty_print_status(&status).await?;
+            }
+        }
+        verb => {
+            let command = simple_command(verb)?;
+            client.send_cmd(&command).await?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::{
+    client::LEDClient,
+    commands::{ColorOrder, Command},
+};
+
+/// Configuration for the ambient screen-color sync daemon.
+#[derive(Clone, Debug)]
+pub struct AmbientConfig {
+    /// How often to sample the screen and push an updated color.
+    pub sample_rate: Duration,
+
+    /// Weight given to the outer edge of the frame versus its center when
+    /// computing the representative color. `0.0` averages the whole frame
+    /// uniformly; higher values bias towards the border, which tends to
+    /// track on-screen content closer to where a bias light actually sits.
+    pub edge_weight: f32,
+}
+
+impl Default for AmbientConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: Duration::from_millis(100),
+            edge_weight: 0.0,
+        }
+    }
+}
+
+/// Permutes an RGB triple captured in natural `R,G,B` order into the byte
+/// order the controller expects for the given `ColorOrder`.
+fn permute_for_order(rgb: [u8; 3], order: &ColorOrder) -> [u8; 3] {
+    let [r, g, b] = rgb;
+    match order {
+        ColorOrder::RGB => [r, g, b],
+        ColorOrder::RBG => [r, b, g],
+        ColorOrder::GRB => [g, r, b],
+        ColorOrder::GBR => [g, b, r],
+        ColorOrder::BRG => [b, r, g],
+        ColorOrder::BGR => [b, g, r],
+    }
+}
+
+/// Downscales a captured frame (tightly packed `BGRA` rows, as returned by
+/// `scrap::Capturer`) to a single representative RGB color, optionally
+/// weighting pixels near the edge of the frame more heavily than the center.
+fn dominant_color(frame: &[u8], width: usize, height: usize, edge_weight: f32) -> [u8; 3] {
+    let mut r_acc = 0f64;
+    let mut g_acc = 0f64;
+    let mut b_acc = 0f64;
+    let mut weight_acc = 0f64;
+
+    let cx = width as f64 / 2.0;
+    let cy = height as f64 / 2.0;
+    let max_dist = (cx * cx + cy * cy).sqrt().max(1.0);
+
+    for y in 0..height {
+        for x in 0..width {
+            let i = (y * width + x) * 4;
+            if i + 2 >= frame.len() {
+                continue;
+            }
+
+            // scrap hands back BGRA rows.
+            let b = frame[i] as f64;
+            let g = frame[i + 1] as f64;
+            let r = frame[i + 2] as f64;
+
+            let dist = ((x as f64 - cx).powi(2) + (y as f64 - cy).powi(2)).sqrt() / max_dist;
+            let weight = 1.0 + edge_weight as f64 * dist;
+
+            r_acc += r * weight;
+            g_acc += g * weight;
+            b_acc += b * weight;
+            weight_acc += weight;
+        }
+    }
+
+    if weight_acc == 0.0 {
+        return [0, 0, 0];
+    }
+
+    [
+        (r_acc / weight_acc).round().clamp(0.0, 255.0) as u8,
+        (g_acc / weight_acc).round().clamp(0.0, 255.0) as u8,
+        (b_acc / weight_acc).round().clamp(0.0, 255.0) as u8,
+    ]
+}
+
+impl LEDClient {
+    /// Runs the ambient bias-light loop forever: repeatedly samples the
+    /// primary display, reduces it to a representative color and streams
+    /// it to the strip as `Command::Color`.
+    ///
+    /// The controller's configured `color_order` is read once up front via
+    /// `get_status()` so the sampled RGB bytes can be permuted into the
+    /// order the controller expects, regardless of `PixelType`/wiring.
+    pub async fn run_ambient(&self, config: AmbientConfig) -> Result<()> {
+        let color_order = self.get_status().await?.color_order;
+
+        let display = scrap::Display::primary()?;
+        let width = display.width();
+        let height = display.height();
+        let mut capturer = scrap::Capturer::new(display)?;
+
+        loop {
+            let frame = loop {
+                match capturer.frame() {
+                    Ok(frame) => break frame.to_vec(),
+                    Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(Duration::from_millis(10));
+                        continue;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            };
+
+            let rgb = dominant_color(&frame, width, height, config.edge_weight);
+            let permuted = permute_for_order(rgb, &color_order);
+
+            self.send_cmd(&Command::Color(permuted)).await?;
+
+            tokio::time::sleep(config.sample_rate).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permute_identity_for_rgb() {
+        assert_eq!(permute_for_order([1, 2, 3], &ColorOrder::RGB), [1, 2, 3]);
+    }
+
+    #[test]
+    fn permute_matches_controller_order() {
+        assert_eq!(permute_for_order([1, 2, 3], &ColorOrder::BGR), [3, 2, 1]);
+        assert_eq!(permute_for_order([1, 2, 3], &ColorOrder::GRB), [2, 1, 3]);
+    }
+
+    #[test]
+    fn dominant_color_of_solid_frame_is_that_color() {
+        let mut frame = Vec::new();
+        for _ in 0..(4 * 4) {
+            frame.extend_from_slice(&[10, 20, 30, 255]); // BGRA
+        }
+
+        assert_eq!(dominant_color(&frame, 4, 4, 0.0), [30, 20, 10]);
+    }
+}
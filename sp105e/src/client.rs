@@ -1,9 +1,13 @@
+use std::pin::Pin;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use bluer::{gatt::remote::Characteristic, Address, Device, Session, Uuid};
+use futures_core::stream::Stream;
 use futures_util::{pin_mut, StreamExt};
 
+use crate::color::fade_steps;
 use crate::commands::{
     Command, StatusResp, GATT_CHARACTERISTIC_UUID, GATT_SERVICE_UUID, STATUS_RETURN_LENGTH,
 };
@@ -65,7 +69,7 @@ impl LEDClient {
         Ok(())
     }
 
-    async fn ensure_connected(&self) -> Result<()> {
+    pub(crate) async fn ensure_connected(&self) -> Result<()> {
         Self::ensure_device_connected(&self.device).await
     }
 
@@ -91,11 +95,14 @@ impl LEDClient {
         })
     }
 
+    pub(crate) async fn write_characteristic(&self, buf: &[u8]) -> Result<()> {
+        self.characteristic.write(buf).await?;
+        Ok(())
+    }
+
     pub async fn send_cmd(&self, command: &Command) -> Result<()> {
         self.ensure_connected().await?;
-        self.characteristic.write(&*command.buf()).await?;
-
-        Ok(())
+        self.write_characteristic(&*command.buf()).await
     }
 
     pub async fn send_cmd_with_callback(
@@ -110,12 +117,17 @@ impl LEDClient {
         Ok(ind)
     }
 
-    pub async fn get_status(&self) -> Result<StatusResp> {
-        let ret = self.send_cmd_with_callback(&Command::Status).await?;
+    /// Reads notifications off an already-subscribed stream until at least
+    /// `STATUS_RETURN_LENGTH` bytes have arrived (or the stream ends) and
+    /// decodes them, shared by `get_status` (which
+    /// opens a fresh subscription per call) and `watch_status` (which
+    /// reuses one long-lived subscription across many reads).
+    async fn collect_status_frame(
+        notify: &mut (impl Stream<Item = Vec<u8>> + Unpin),
+    ) -> Result<StatusResp> {
         let mut res: Vec<u8> = Vec::new();
-        pin_mut!(ret);
         while res.len() < STATUS_RETURN_LENGTH as usize {
-            match ret.next().await {
+            match notify.next().await {
                 Some(value) => res.extend(value),
                 None => {
                     println!("notification session terminated prematurely");
@@ -126,6 +138,149 @@ impl LEDClient {
 
         res.try_into()
     }
+
+    pub async fn get_status(&self) -> Result<StatusResp> {
+        let ret = self.send_cmd_with_callback(&Command::Status).await?;
+        pin_mut!(ret);
+        Self::collect_status_frame(&mut ret).await
+    }
+
+    /// Keeps a single GATT notification subscription open and re-triggers
+    /// it by writing `Command::Status` on `interval`, emitting a new
+    /// `StatusResp` only when it differs from the last one observed
+    /// (`changed` on the emitted value names exactly which fields those
+    /// are) — an event-driven model on top of the otherwise one-shot
+    /// `get_status()`, without repeatedly opening and tearing down a fresh
+    /// subscription per poll.
+    pub fn watch_status(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<StatusResp>> + '_ {
+        type Notify = Pin<Box<dyn Stream<Item = Vec<u8>> + Send>>;
+
+        futures_util::stream::unfold(
+            None::<(Notify, Option<StatusResp>)>,
+            move |state| async move {
+                let (mut notify, mut last) = match state {
+                    Some(state) => {
+                        tokio::time::sleep(interval).await;
+                        state
+                    }
+                    None => {
+                        if let Err(e) = self.ensure_connected().await {
+                            return Some((Err(e), None));
+                        }
+
+                        match self.characteristic.notify().await {
+                            Ok(notify) => (Box::pin(notify) as Notify, None),
+                            Err(e) => return Some((Err(e.into()), None)),
+                        }
+                    }
+                };
+
+                loop {
+                    if let Err(e) = self.write_characteristic(&*Command::Status.buf()).await {
+                        return Some((Err(e), None));
+                    }
+
+                    let mut status = match Self::collect_status_frame(&mut notify).await {
+                        Ok(status) => status,
+                        Err(e) => return Some((Err(e), None)),
+                    };
+
+                    if let Some(previous) = &last {
+                        status.changed = status.diff(previous);
+                        if status.changed.is_empty() {
+                            tokio::time::sleep(interval).await;
+                            continue;
+                        }
+                    }
+
+                    last = Some(status.clone());
+                    return Some((Ok(status), Some((notify, last))));
+                }
+            },
+        )
+    }
+
+    /// Fades the strip's color from `from` to `to` over `duration`, emitting
+    /// `Command::Color` for `steps + 1` colors (both endpoints included) interpolated in
+    /// CIELAB space rather than raw RGB, so the transition looks visually
+    /// even instead of passing through a muddy, darker-than-expected
+    /// midpoint. Sleeps `duration / steps` between frames; if `steps == 0`
+    /// only the `to` endpoint is sent.
+    pub async fn fade(&self, from: [u8; 3], to: [u8; 3], duration: Duration, steps: u32) -> Result<()> {
+        let frame_delay = if steps == 0 {
+            Duration::ZERO
+        } else {
+            duration / steps
+        };
+
+        let colors = fade_steps(from, to, steps);
+        let last = colors.len() - 1;
+
+        for (i, color) in colors.into_iter().enumerate() {
+            self.send_cmd(&Command::Color(color)).await?;
+
+            if i < last && !frame_delay.is_zero() {
+                tokio::time::sleep(frame_delay).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Issues `count` repeats of `up`/`down`, separated by a short delay so
+    /// consecutive BLE characteristic writes reliably land on the
+    /// controller. `count` is signed: positive nudges with `up`, negative
+    /// with `down`.
+    async fn nudge(&self, up: Command, down: Command, count: i8) -> Result<()> {
+        let (command, repeats) = if count >= 0 {
+            (up, count)
+        } else {
+            (down, -count)
+        };
+
+        for _ in 0..repeats {
+            self.send_cmd(&command).await?;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        Ok(())
+    }
+
+    /// Sets the strip's brightness to an absolute `level` in `[0, 6]` by
+    /// reading the controller's current brightness via `get_status()` and
+    /// issuing exactly as many `BrightnessUp`/`BrightnessDown` commands as
+    /// needed to close the gap, instead of requiring the caller to guess
+    /// how many nudges that takes.
+    pub async fn set_brightness(&self, level: u8) -> Result<()> {
+        if !(0..=6).contains(&level) {
+            return Err(anyhow!("brightness level {level} is out of range [0, 6]"));
+        }
+
+        let current = self.get_status().await?.brightness;
+        let delta = i8::try_from(level as i16 - current as i16)
+            .expect("both operands are in [0, 6], so their difference fits in an i8");
+
+        self.nudge(Command::BrightnessUp, Command::BrightnessDown, delta)
+            .await
+    }
+
+    /// Sets the animation/fixed-color speed to an absolute `level` in
+    /// `[0, 6]`, analogous to `set_brightness`.
+    pub async fn set_speed(&self, level: u8) -> Result<()> {
+        if !(0..=6).contains(&level) {
+            return Err(anyhow!("speed level {level} is out of range [0, 6]"));
+        }
+
+        let current = self.get_status().await?.speed;
+        let delta = i8::try_from(level as i16 - current as i16)
+            .expect("both operands are in [0, 6], so their difference fits in an i8");
+
+        self.nudge(Command::SpeedUp, Command::SpeedDown, delta)
+            .await
+    }
 }
 
 #[cfg(test)]
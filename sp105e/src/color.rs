@@ -0,0 +1,144 @@
+//! sRGB <-> CIELAB conversion helpers used for perceptually-even color fades.
+
+/// D65 reference white, used to normalize XYZ before the Lab nonlinearity.
+const REF_X: f64 = 95.047;
+const REF_Y: f64 = 100.0;
+const REF_Z: f64 = 108.883;
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn lab_f(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+    if t > DELTA.powi(3) {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f64) -> f64 {
+    const DELTA: f64 = 6.0 / 29.0;
+    if t > DELTA {
+        t.powi(3)
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+/// Converts an sRGB color to CIELAB (`L*`, `a*`, `b*`).
+pub fn srgb_to_lab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+
+    let x = 100.0 * (0.4124 * r + 0.3576 * g + 0.1805 * b);
+    let y = 100.0 * (0.2126 * r + 0.7152 * g + 0.0722 * b);
+    let z = 100.0 * (0.0193 * r + 0.1192 * g + 0.9505 * b);
+
+    let fx = lab_f(x / REF_X);
+    let fy = lab_f(y / REF_Y);
+    let fz = lab_f(z / REF_Z);
+
+    [
+        116.0 * fy - 16.0,
+        500.0 * (fx - fy),
+        200.0 * (fy - fz),
+    ]
+}
+
+/// Converts a CIELAB color back to sRGB, gamma-correcting and clamping each
+/// channel to `0..=255`.
+pub fn lab_to_srgb(lab: [f64; 3]) -> [u8; 3] {
+    let [l, a, b] = lab;
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let x = REF_X * lab_f_inv(fx);
+    let y = REF_Y * lab_f_inv(fy);
+    let z = REF_Z * lab_f_inv(fz);
+
+    let x = x / 100.0;
+    let y = y / 100.0;
+    let z = z / 100.0;
+
+    let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
+    let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
+    let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+
+    [r, g, b].map(linear_to_srgb)
+}
+
+/// Produces `steps + 1` RGB colors tracing a perceptually-even fade from
+/// `from` to `to`, interpolating linearly in CIELAB space. Returns just the
+/// endpoint when `steps == 0`.
+pub fn fade_steps(from: [u8; 3], to: [u8; 3], steps: u32) -> Vec<[u8; 3]> {
+    if steps == 0 {
+        return vec![to];
+    }
+
+    let lab_from = srgb_to_lab(from);
+    let lab_to = srgb_to_lab(to);
+
+    (0..=steps)
+        .map(|i| {
+            let t = i as f64 / steps as f64;
+            let lab = [
+                lab_from[0] + (lab_to[0] - lab_from[0]) * t,
+                lab_from[1] + (lab_to[1] - lab_from[1]) * t,
+                lab_from[2] + (lab_to[2] - lab_from[2]) * t,
+            ];
+            lab_to_srgb(lab)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_is_lossless_within_rounding() {
+        for rgb in [[0, 0, 0], [255, 255, 255], [18, 52, 86], [200, 10, 240]] {
+            let lab = srgb_to_lab(rgb);
+            let back = lab_to_srgb(lab);
+            for (a, b) in rgb.iter().zip(back.iter()) {
+                assert!((*a as i16 - *b as i16).abs() <= 1, "{rgb:?} -> {back:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn fade_endpoints_match_input() {
+        let steps = fade_steps([0, 0, 0], [255, 255, 255], 10);
+        assert_eq!(steps.len(), 11);
+        assert_eq!(steps[0], [0, 0, 0]);
+
+        let last = steps[10];
+        for c in last {
+            assert!(c >= 250, "expected near-white, got {last:?}");
+        }
+    }
+
+    #[test]
+    fn zero_steps_sends_only_the_endpoint() {
+        assert_eq!(fade_steps([10, 20, 30], [40, 50, 60], 0), vec![[40, 50, 60]]);
+    }
+}
@@ -79,6 +79,7 @@ pub enum Command {
 
 #[derive(PartialEq, Eq, Debug, Clone, Default)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "rules", derive(serde::Deserialize))]
 #[repr(u8)]
 pub enum ColorOrder {
     #[default]
@@ -92,6 +93,7 @@ pub enum ColorOrder {
 
 #[derive(PartialEq, Eq, Debug, Clone, Default)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "rules", derive(serde::Deserialize))]
 #[repr(u8)]
 pub enum PixelType {
     SM16703,
@@ -124,6 +126,19 @@ pub enum PixelType {
     SK9822,
 }
 
+/// One field of `StatusResp`, used to report which fields changed between
+/// two observations (see `LEDClient::watch_status`).
+#[derive(enumset::EnumSetType, Debug)]
+pub enum StatusField {
+    Power,
+    Mode,
+    Speed,
+    Brightness,
+    PixelType,
+    ColorOrder,
+    ControllerId,
+}
+
 /// Struct representation of the bytes returned by the status command
 /// The order of the `u8`s in the struct corresponds directly to the
 /// bytes.
@@ -156,9 +171,46 @@ pub struct StatusResp {
     /// Range: see `enum ColorOrder`
     pub color_order: ColorOrder,
 
-    /// Rest bytes in status message (function unknown)
-    /// Always seem to be 0x01 0xf4 (maybe some controller ID?)
-    pub _unknown: [u8; 2],
+    /// Identifier of the controller, read from the previously-reserved
+    /// trailing bytes. Always seems to be 0x01f4 on the hardware we've seen.
+    pub controller_id: u16,
+
+    /// Fields that differ from the previously observed `StatusResp`, as
+    /// seen by `LEDClient::watch_status()`. A one-shot `get_status()` call
+    /// has no prior observation to diff against, so this is always
+    /// `EnumSet::all()` in that case.
+    pub changed: enumset::EnumSet<StatusField>,
+}
+
+impl StatusResp {
+    /// Computes which fields differ between `self` and `previous`.
+    pub(crate) fn diff(&self, previous: &StatusResp) -> enumset::EnumSet<StatusField> {
+        let mut changed = enumset::EnumSet::new();
+
+        if self.power != previous.power {
+            changed |= StatusField::Power;
+        }
+        if self.mode != previous.mode {
+            changed |= StatusField::Mode;
+        }
+        if self.speed != previous.speed {
+            changed |= StatusField::Speed;
+        }
+        if self.brightness != previous.brightness {
+            changed |= StatusField::Brightness;
+        }
+        if self.pixel_type != previous.pixel_type {
+            changed |= StatusField::PixelType;
+        }
+        if self.color_order != previous.color_order {
+            changed |= StatusField::ColorOrder;
+        }
+        if self.controller_id != previous.controller_id {
+            changed |= StatusField::ControllerId;
+        }
+
+        changed
+    }
 }
 
 impl TryFrom<Vec<u8>> for StatusResp {
@@ -169,7 +221,7 @@ impl TryFrom<Vec<u8>> for StatusResp {
             return Err(anyhow!("status vector has wrong size"));
         }
 
-        let [power, mode_v, speed, brightness, pixel_type_v, color_order_v, u1, u2]: [u8] =
+        let [power, mode_v, speed, brightness, pixel_type_v, color_order_v, id_hi, id_lo]: [u8] =
             value[..]
         else {
             return Err(anyhow!("could not unpack status vector!"));
@@ -204,7 +256,7 @@ impl TryFrom<Vec<u8>> for StatusResp {
         // SAFETY: we have already checked that the enum has this value!
         let color_order: ColorOrder = unsafe { transmute(color_order_v) };
 
-        let _unknown = [u1, u2];
+        let controller_id = u16::from_be_bytes([id_hi, id_lo]);
 
         Ok(StatusResp {
             power,
@@ -213,7 +265,8 @@ impl TryFrom<Vec<u8>> for StatusResp {
             brightness,
             pixel_type,
             color_order,
-            _unknown,
+            controller_id,
+            changed: enumset::EnumSet::all(),
         })
     }
 }
@@ -302,4 +355,26 @@ mod tests {
 
         assert_eq!(*result, [COMMAND_PREFIX, ordinal, 0, 0, 0x3C]);
     }
+
+    fn status_bytes(speed: u8, brightness: u8, controller_id: u16) -> Vec<u8> {
+        let [id_hi, id_lo] = controller_id.to_be_bytes();
+        vec![1, 0xc9, speed, brightness, 0, 0, id_hi, id_lo]
+    }
+
+    #[test]
+    fn status_decodes_controller_id_big_endian() {
+        let status = StatusResp::try_from(status_bytes(3, 4, 0x01f4)).unwrap();
+
+        assert_eq!(status.controller_id, 0x01f4);
+    }
+
+    #[test]
+    fn status_diff_reports_only_changed_fields() {
+        let previous = StatusResp::try_from(status_bytes(3, 4, 0x01f4)).unwrap();
+        let current = StatusResp::try_from(status_bytes(5, 4, 0x01f4)).unwrap();
+
+        let changed = current.diff(&previous);
+
+        assert_eq!(changed, StatusField::Speed.into());
+    }
 }
@@ -0,0 +1,99 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio::sync::Barrier;
+
+use crate::client::LEDClient;
+use crate::commands::Command;
+
+/// A set of `LEDClient`s driven together, so the same `Command` reaches
+/// every device as close to simultaneously as possible (e.g. starting the
+/// same `Command::Animation` on strips that should stay in phase).
+pub struct ClientGroup {
+    clients: Vec<Arc<LEDClient>>,
+}
+
+impl LEDClient {
+    /// Connects to every MAC in `targets` and returns a `ClientGroup` that
+    /// dispatches commands to all of them in lockstep. Each connection
+    /// runs on its own spawned task, so one device stuck in
+    /// `ensure_device_connected`'s blocking retry sleep can't hold up the
+    /// others.
+    pub async fn group(adapter_name: Option<String>, targets: Vec<String>) -> Result<ClientGroup> {
+        let tasks: Vec<_> = targets
+            .into_iter()
+            .map(|target| {
+                let adapter_name = adapter_name.clone();
+                tokio::spawn(async move { LEDClient::new(adapter_name, target).await })
+            })
+            .collect();
+
+        let mut clients = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            clients.push(Arc::new(task.await??));
+        }
+
+        Ok(ClientGroup { clients })
+    }
+}
+
+impl ClientGroup {
+    /// Spawns `f` as its own task for every client in the group and awaits
+    /// the tasks in order, so a single device stalling inside `f` (e.g.
+    /// reconnecting) doesn't delay the rest. Returns the first error met in
+    /// that order without waiting for the remaining tasks.
+    async fn spawn_all<F, Fut>(&self, f: F) -> Result<()>
+    where
+        F: Fn(Arc<LEDClient>) -> Fut,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let tasks: Vec<_> = self
+            .clients
+            .iter()
+            .cloned()
+            .map(|client| tokio::spawn(f(client)))
+            .collect();
+
+        for task in tasks {
+            task.await??;
+        }
+
+        Ok(())
+    }
+
+    /// Sends `command` to every client in the group. Each device connects
+    /// and prepares its characteristic independently on its own task, then
+    /// waits on a shared barrier so that once every device is ready, all of
+    /// their writes fire in the same instant.
+    pub async fn send_cmd(&self, command: &Command) -> Result<()> {
+        let barrier = Arc::new(Barrier::new(self.clients.len()));
+        let buf = Arc::new(*command.buf());
+
+        self.spawn_all(move |client| {
+            let barrier = barrier.clone();
+            let buf = buf.clone();
+            async move {
+                client.ensure_connected().await?;
+                barrier.wait().await;
+                client.write_characteristic(&buf[..]).await
+            }
+        })
+        .await
+    }
+
+    /// Sets every client in the group to an absolute brightness `level`,
+    /// concurrently, so strips that should stay in phase reach the target
+    /// level at roughly the same time instead of one after another.
+    pub async fn set_brightness(&self, level: u8) -> Result<()> {
+        self.spawn_all(move |client| async move { client.set_brightness(level).await })
+            .await
+    }
+
+    /// Sets every client in the group to an absolute speed `level`,
+    /// analogous to `set_brightness`.
+    pub async fn set_speed(&self, level: u8) -> Result<()> {
+        self.spawn_all(move |client| async move { client.set_speed(level).await })
+            .await
+    }
+}
@@ -1,6 +1,21 @@
 /// Holds basic definitions of all the commands
 pub mod commands;
 
+/// sRGB/CIELAB color conversion helpers, used for perceptually-even fades
+pub mod color;
+
 /// Defines the BlueZ LED client that connects to the controller
 #[cfg(feature = "client")]
 pub mod client;
+
+/// Screen-ambient color sync daemon built on top of `LEDClient`
+#[cfg(all(feature = "client", feature = "ambient"))]
+pub mod ambient;
+
+/// Config-driven event-to-command rule engine
+#[cfg(all(feature = "client", feature = "rules"))]
+pub mod rules;
+
+/// Synchronized control of several `LEDClient`s at once
+#[cfg(feature = "client")]
+pub mod group;
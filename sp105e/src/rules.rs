@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use crate::client::LEDClient;
+use crate::commands::{Command, ColorOrder, PixelType};
+
+/// A palette entry: either an inline `[r, g, b]` triplet or a `"#rrggbb"`
+/// hex string.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PaletteColor {
+    Triplet([u8; 3]),
+    Hex(String),
+}
+
+impl PaletteColor {
+    fn resolve(&self) -> Result<[u8; 3]> {
+        match self {
+            PaletteColor::Triplet(rgb) => Ok(*rgb),
+            PaletteColor::Hex(hex) => {
+                let hex = hex.trim_start_matches('#');
+                if hex.len() != 6 {
+                    return Err(anyhow!("palette color {hex:?} is not a 6-digit hex string"));
+                }
+
+                Ok([
+                    u8::from_str_radix(&hex[0..2], 16)?,
+                    u8::from_str_radix(&hex[2..4], 16)?,
+                    u8::from_str_radix(&hex[4..6], 16)?,
+                ])
+            }
+        }
+    }
+}
+
+/// A named profile, merged onto `RulesConfig::default` to produce the
+/// actual set of actions dispatched for a trigger. Any field left `None`
+/// falls back to the corresponding field in the default profile.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RuleDef {
+    pub color_order: Option<ColorOrder>,
+    pub pixel_type: Option<PixelType>,
+    /// Either a key into `RulesConfig::palette` or an inline `"#rrggbb"`.
+    pub color: Option<String>,
+    pub animation: Option<u8>,
+    pub speed: Option<u8>,
+    pub brightness: Option<u8>,
+}
+
+impl RuleDef {
+    /// Merges `self` onto `base`: every field set in `self` wins, every
+    /// unset field falls through to `base`'s value.
+    pub fn merge(&self, base: &RuleDef) -> RuleDef {
+        RuleDef {
+            color_order: self.color_order.clone().or_else(|| base.color_order.clone()),
+            pixel_type: self.pixel_type.clone().or_else(|| base.pixel_type.clone()),
+            color: self.color.clone().or_else(|| base.color.clone()),
+            animation: self.animation.or(base.animation),
+            speed: self.speed.or(base.speed),
+            brightness: self.brightness.or(base.brightness),
+        }
+    }
+}
+
+/// A declarative event-to-command profile, loaded from YAML or TOML.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RulesConfig {
+    /// Reusable named colors, referenced by `RuleDef::color`.
+    #[serde(default)]
+    pub palette: HashMap<String, PaletteColor>,
+
+    /// Fields every trigger's rule falls back to when left unset.
+    #[serde(default)]
+    pub default: RuleDef,
+
+    /// Named triggers, e.g. `"alert"`, `"idle"`.
+    #[serde(default)]
+    pub rules: HashMap<String, RuleDef>,
+}
+
+/// One step of the sequence dispatched for a resolved trigger.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Command(Command),
+    SetSpeed(u8),
+    SetBrightness(u8),
+}
+
+impl RulesConfig {
+    /// Loads a `RulesConfig` from a `.yaml`/`.yml` or `.toml` file, picked
+    /// by the path's extension.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Ok(serde_yaml::from_str(&contents)?),
+            Some("toml") => Ok(toml::from_str(&contents)?),
+            other => Err(anyhow!(
+                "unsupported rules config extension {other:?}, expected .yaml/.yml/.toml"
+            )),
+        }
+    }
+
+    /// Resolves a trigger into its ordered `Action` sequence: the rule
+    /// named `trigger` merged onto `default`, turned into `SetColorOrder`,
+    /// `SetPixelType`, `Color`, `Animation`, and absolute speed/brightness
+    /// actions for whichever fields ended up set.
+    pub fn resolve(&self, trigger: &str) -> Result<Vec<Action>> {
+        let rule = self
+            .rules
+            .get(trigger)
+            .ok_or_else(|| anyhow!("no rule named {trigger:?}"))?
+            .merge(&self.default);
+
+        let mut actions = Vec::new();
+
+        if let Some(order) = rule.color_order {
+            actions.push(Action::Command(Command::SetColorOrder(order)));
+        }
+
+        if let Some(pixel_type) = rule.pixel_type {
+            actions.push(Action::Command(Command::SetPixelType(pixel_type)));
+        }
+
+        if let Some(color) = &rule.color {
+            let rgb = match self.palette.get(color) {
+                Some(entry) => entry.resolve()?,
+                None => PaletteColor::Hex(color.clone()).resolve()?,
+            };
+            actions.push(Action::Command(Command::Color(rgb)));
+        }
+
+        if let Some(id) = rule.animation {
+            actions.push(Action::Command(Command::Animation(id)));
+        }
+
+        if let Some(level) = rule.speed {
+            actions.push(Action::SetSpeed(level));
+        }
+
+        if let Some(level) = rule.brightness {
+            actions.push(Action::SetBrightness(level));
+        }
+
+        Ok(actions)
+    }
+}
+
+impl LEDClient {
+    /// Resolves `trigger` against `config` and dispatches its action
+    /// sequence in order.
+    pub async fn run_rule(&self, config: &RulesConfig, trigger: &str) -> Result<()> {
+        for action in config.resolve(trigger)? {
+            match action {
+                Action::Command(command) => self.send_cmd(&command).await?,
+                Action::SetSpeed(level) => self.set_speed(level).await?,
+                Action::SetBrightness(level) => self.set_brightness(level).await?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RulesConfig {
+        let mut palette = HashMap::new();
+        palette.insert("alert_red".to_string(), PaletteColor::Hex("#ff0000".to_string()));
+
+        let mut rules = HashMap::new();
+        rules.insert(
+            "alert".to_string(),
+            RuleDef {
+                color: Some("alert_red".to_string()),
+                animation: Some(0),
+                ..Default::default()
+            },
+        );
+
+        RulesConfig {
+            palette,
+            default: RuleDef {
+                speed: Some(3),
+                brightness: Some(4),
+                ..Default::default()
+            },
+            rules,
+        }
+    }
+
+    #[test]
+    fn merge_falls_back_to_default() {
+        let cfg = config();
+        let actions = cfg.resolve("alert").unwrap();
+
+        assert_eq!(
+            actions,
+            vec![
+                Action::Command(Command::Color([0xff, 0, 0])),
+                Action::Command(Command::Animation(0)),
+                Action::SetSpeed(3),
+                Action::SetBrightness(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_trigger_is_an_error() {
+        assert!(config().resolve("nonexistent").is_err());
+    }
+
+    #[test]
+    fn inline_hex_color_without_palette_entry() {
+        let mut rules = HashMap::new();
+        rules.insert(
+            "direct".to_string(),
+            RuleDef {
+                color: Some("#00ff00".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let cfg = RulesConfig {
+            rules,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            cfg.resolve("direct").unwrap(),
+            vec![Action::Command(Command::Color([0, 0xff, 0]))]
+        );
+    }
+}